@@ -4,6 +4,8 @@ extern crate base16;
 use unicode_segmentation::UnicodeSegmentation;
 use std::vec::Vec;
 use std::slice;
+use std::fmt;
+use std::str::FromStr;
 
 const RGB_MAX: f64 = 255.0;
 
@@ -29,6 +31,13 @@ pub struct HSLA {
     pub a: f64
 }
 
+#[derive(Debug, PartialEq)]
+pub struct HSV {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64
+}
+
 #[derive(Debug, PartialEq)]
 pub struct RGB {
     pub r: u8,
@@ -44,6 +53,25 @@ pub struct RGBA {
     pub a: f64
 }
 
+#[derive(Debug, PartialEq)]
+pub struct ParseColorError {
+    pub message: String
+}
+
+impl ParseColorError {
+    fn new<S: Into<String>>(message: S) -> ParseColorError {
+        ParseColorError { message: message.into() }
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
 #[derive(Debug, PartialEq)]
 pub struct Color {
     pub r: u8,
@@ -132,6 +160,48 @@ impl Color {
         return Color::create(to_rgb(r), to_rgb(g), to_rgb(b), 1.0)
     }
 
+    pub fn from_hsv(hue: f64, saturation: f64, value: f64) -> Color {
+        fn to_rgb(n: f64) -> u8 { round(n * 255.0, 0.0) as u8 }
+        let h = hue / 60.0;
+        let s = to_decimal(saturation);
+        let v = to_decimal(value);
+        let i = h.floor();
+        let f = h - i;
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - (f * s));
+        let t = v * (1.0 - ((1.0 - f) * s));
+
+        let (r, g, b) = match (i as i64).rem_euclid(6) {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q)
+        };
+
+        return Color::create(to_rgb(r), to_rgb(g), to_rgb(b), 1.0)
+    }
+
+    // packs/unpacks a color as a single `0xRRGGBBAA` word: red in the high
+    // byte, then green, blue, and the alpha byte (the `0.0..=1.0` float scaled
+    // by 255). the RGB bytes line up with `hex()`, so the two stay consistent.
+    pub fn from_u32(value: u32) -> Color {
+        let r = ((value >> 24) & 0xFF) as u8;
+        let g = ((value >> 16) & 0xFF) as u8;
+        let b = ((value >> 8) & 0xFF) as u8;
+        let a = (value & 0xFF) as f64 / RGB_MAX;
+        Color::create(r, g, b, a)
+    }
+
+    pub fn to_u32(&self) -> u32 {
+        let a = round(self.a * RGB_MAX, 0.0) as u32;
+        ((self.r as u32) << 24)
+            | ((self.g as u32) << 16)
+            | ((self.b as u32) << 8)
+            | (a & 0xFF)
+    }
+
     pub fn r(&mut self, r: u8) -> &mut Self {
         self.r = r;
         self
@@ -226,6 +296,48 @@ impl Color {
         }
     }
 
+    pub fn hsv(&self) -> HSV {
+        let r = self.r as f64 / RGB_MAX;
+        let g = self.g as f64 / RGB_MAX;
+        let b = self.b as f64 / RGB_MAX;
+        let min = r.min(b.min(g));
+        let max = r.max(b.max(g));
+        let delta = max - min;
+        let mut h:f64 = 0.0;
+        let s:f64;
+        let v:f64;
+
+        if max == min {
+            h = 0.0;
+        } else if r == max{
+            h = (g - b) / delta;
+        } else if g == max {
+            h = 2.0 + (b - r) / delta;
+        } else if b == max {
+            h = 4.0 + (r -g ) / delta;
+        }
+
+        h = (h * 60.0).min(360.0);
+
+        if h < 0.0{
+            h += 360.0;
+        }
+
+        v = max;
+
+        if max == 0.0 {
+            s = 0.0;
+        } else {
+            s = delta / max;
+        }
+
+        HSV {
+            h: round(h, 0.0),
+            s: round(s, 2.0),
+            v: round(v, 2.0)
+        }
+    }
+
     pub fn to_hsla_string(&self) -> String {
         let HSLA { h, s, l, a } = self.hsla();
 
@@ -254,10 +366,271 @@ impl Color {
     pub fn is_transparent(&self) -> bool {
         self.a == 0.0
     }
+
+    // see: https://www.w3.org/TR/WCAG20/#relativeluminancedef
+    pub fn relative_luminance(&self) -> f64 {
+        fn linearize(c: f64) -> f64 {
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        let r = linearize(self.r as f64 / RGB_MAX);
+        let g = linearize(self.g as f64 / RGB_MAX);
+        let b = linearize(self.b as f64 / RGB_MAX);
+
+        (0.2126 * r) + (0.7152 * g) + (0.0722 * b)
+    }
+
+    // see: https://www.w3.org/TR/WCAG20/#contrast-ratiodef
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let l1 = self.relative_luminance();
+        let l2 = other.relative_luminance();
+        let (lighter, darker) = if l1 >= l2 { (l1, l2) } else { (l2, l1) };
+
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    pub fn meets_wcag_aa(&self, other: &Color) -> bool {
+        self.contrast_ratio(other) >= 4.5
+    }
+
+    pub fn inverted(&self) -> Color {
+        Color::create(255 - self.r, 255 - self.g, 255 - self.b, self.a)
+    }
+
+    pub fn complement(&self) -> Color {
+        let HSLA { h, s, l, a } = self.hsla();
+        let mut color = Color::from_hsl((h + 180.0) % 360.0, s, l);
+        color.a(a);
+        color
+    }
+
+    pub fn lighten(&self, amount: f64) -> Color {
+        let HSLA { h, s, l, a } = self.hsla();
+        let mut color = Color::from_hsl(h, s, clamp(l + to_decimal(amount)));
+        color.a(a);
+        color
+    }
+
+    pub fn darken(&self, amount: f64) -> Color {
+        let HSLA { h, s, l, a } = self.hsla();
+        let mut color = Color::from_hsl(h, s, clamp(l - to_decimal(amount)));
+        color.a(a);
+        color
+    }
+
+    pub fn saturate(&self, amount: f64) -> Color {
+        let HSLA { h, s, l, a } = self.hsla();
+        let mut color = Color::from_hsl(h, clamp(s + to_decimal(amount)), l);
+        color.a(a);
+        color
+    }
+
+    pub fn desaturate(&self, amount: f64) -> Color {
+        let HSLA { h, s, l, a } = self.hsla();
+        let mut color = Color::from_hsl(h, clamp(s - to_decimal(amount)), l);
+        color.a(a);
+        color
+    }
+
+    pub fn lerp(&self, other: &Color, t: f64) -> Color {
+        let t = match t {
+            t if t < 0.0 => 0.0,
+            t if t > 1.0 => 1.0,
+            _ => t
+        };
+        Color::create(
+            mix(self.r, other.r, t),
+            mix(self.g, other.g, t),
+            mix(self.b, other.b, t),
+            (1.0 - t) * self.a + t * other.a
+        )
+    }
+
+    pub fn gradient(stops: &[Color], steps: usize) -> Vec<Color> {
+        let mut colors: Vec<Color> = Vec::new();
+
+        if stops.is_empty() || steps == 0 {
+            return colors;
+        }
+
+        if stops.len() == 1 || steps == 1 {
+            let stop = &stops[0];
+            for _ in 0..steps {
+                colors.push(Color::create(stop.r, stop.g, stop.b, stop.a));
+            }
+            return colors;
+        }
+
+        let segments = (stops.len() - 1) as f64;
+
+        for i in 0..steps {
+            let pos = i as f64 / (steps - 1) as f64 * segments;
+            let idx = (pos.floor() as usize).min(stops.len() - 2);
+            let t = pos - idx as f64;
+            colors.push(stops[idx].lerp(&stops[idx + 1], t));
+        }
+
+        colors
+    }
+}
+
+
+impl std::ops::Add for Color {
+    type Output = Color;
+
+    fn add(self, other: Color) -> Color {
+        Color::create(
+            saturate_channel(self.r as f64 + other.r as f64),
+            saturate_channel(self.g as f64 + other.g as f64),
+            saturate_channel(self.b as f64 + other.b as f64),
+            (self.a + other.a) / 2.0
+        )
+    }
+}
+
+impl std::ops::Sub for Color {
+    type Output = Color;
+
+    fn sub(self, other: Color) -> Color {
+        Color::create(
+            saturate_channel(self.r as f64 - other.r as f64),
+            saturate_channel(self.g as f64 - other.g as f64),
+            saturate_channel(self.b as f64 - other.b as f64),
+            (self.a + other.a) / 2.0
+        )
+    }
+}
+
+impl std::ops::Mul<f64> for Color {
+    type Output = Color;
+
+    fn mul(self, scalar: f64) -> Color {
+        Color::create(
+            saturate_channel(self.r as f64 * scalar),
+            saturate_channel(self.g as f64 * scalar),
+            saturate_channel(self.b as f64 * scalar),
+            self.a
+        )
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let lower = trimmed.to_lowercase();
+
+        if lower.starts_with("rgb") {
+            parse_rgb(trimmed)
+        } else if lower.starts_with("hsl") {
+            parse_hsl(trimmed)
+        } else {
+            parse_hex(trimmed)
+        }
+    }
 }
 
 
 // UTILS //////////////////////////////////////////////////////////////////////
+fn parse_hex(input: &str) -> Result<Color, ParseColorError> {
+    let hex = input.trim_start_matches('#');
+
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ParseColorError::new(format!("invalid hex digits in `{}`", hex)));
+    }
+
+    let expanded = match hex.len() {
+        3 => hex.chars().flat_map(|c| vec![c, c]).collect::<String>(),
+        6 | 8 => hex.to_string(),
+        n => return Err(ParseColorError::new(
+            format!("expected 3, 6, or 8 hex digits, found {}", n)
+        ))
+    };
+
+    let byte = |pair: &str| match base16::decode(pair) {
+        Ok(v) => Ok(v[0]),
+        Err(_) => Err(ParseColorError::new(format!("invalid hex component `{}`", pair)))
+    };
+
+    let r = byte(&expanded[0..2])?;
+    let g = byte(&expanded[2..4])?;
+    let b = byte(&expanded[4..6])?;
+    let a = match expanded.len() {
+        8 => byte(&expanded[6..8])? as f64 / RGB_MAX,
+        _ => 1.0
+    };
+
+    Ok(Color::create(r, g, b, a))
+}
+
+fn parse_rgb(input: &str) -> Result<Color, ParseColorError> {
+    let parts = fn_args(input)?;
+    let r = parse_channel(&parts[0])?;
+    let g = parse_channel(get(&parts, 1)?)?;
+    let b = parse_channel(get(&parts, 2)?)?;
+
+    match parts.len() {
+        3 => Ok(Color::create(r, g, b, 1.0)),
+        4 => Ok(Color::create(r, g, b, parse_float(&parts[3])?)),
+        n => Err(ParseColorError::new(
+            format!("expected 3 or 4 rgb components, found {}", n)
+        ))
+    }
+}
+
+fn parse_hsl(input: &str) -> Result<Color, ParseColorError> {
+    let parts = fn_args(input)?;
+    let h = parse_float(&parts[0])?;
+    let s = parse_float(get(&parts, 1)?)?;
+    let l = parse_float(get(&parts, 2)?)?;
+
+    match parts.len() {
+        3 => Ok(Color::from_hsl(h, s, l)),
+        4 => {
+            let a = parse_float(&parts[3])?;
+            let mut color = Color::from_hsl(h, s, l);
+            color.a(a);
+            Ok(color)
+        },
+        n => Err(ParseColorError::new(
+            format!("expected 3 or 4 hsl components, found {}", n)
+        ))
+    }
+}
+
+fn fn_args(input: &str) -> Result<Vec<String>, ParseColorError> {
+    match (input.find('('), input.rfind(')')) {
+        (Some(start), Some(end)) if end > start => Ok(
+            input[start + 1..end]
+                .split(',')
+                .map(|p| p.trim().to_string())
+                .collect()
+        ),
+        _ => Err(ParseColorError::new(
+            format!("malformed function syntax in `{}`", input)
+        ))
+    }
+}
+
+fn get<'a>(parts: &'a [String], i: usize) -> Result<&'a String, ParseColorError> {
+    parts.get(i).ok_or_else(|| ParseColorError::new("missing color component"))
+}
+
+fn parse_channel(s: &str) -> Result<u8, ParseColorError> {
+    s.trim().parse::<u8>()
+        .map_err(|_| ParseColorError::new(format!("invalid color channel `{}`", s)))
+}
+
+fn parse_float(s: &str) -> Result<f64, ParseColorError> {
+    let s = s.trim().trim_end_matches('%').trim();
+    s.parse::<f64>()
+        .map_err(|_| ParseColorError::new(format!("invalid numeric component `{}`", s)))
+}
+
 fn hue_to_rgb(p: f64, q: f64, mut t: f64) -> f64{
     if t < 0.0 { t += 1.0; }
     if t > 1.0 { t -= 1.0; }
@@ -268,6 +641,26 @@ fn hue_to_rgb(p: f64, q: f64, mut t: f64) -> f64{
 }
 
 
+fn saturate_channel(n: f64) -> u8 {
+    match n.round() {
+        n if n < 0.0 => 0,
+        n if n > 255.0 => 255,
+        n => n as u8
+    }
+}
+
+fn clamp(n: f64) -> f64 {
+    match n {
+        n if n < 0.0 => 0.0,
+        n if n > 1.0 => 1.0,
+        _ => n
+    }
+}
+
+fn mix(c0: u8, c1: u8, t: f64) -> u8 {
+    ((1.0 - t) * c0 as f64 + t * c1 as f64).round() as u8
+}
+
 fn to_decimal(n: f64) -> f64 {
     match n > 1.0 {
         true => n / 100.0,
@@ -533,6 +926,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn hsv() {
+        let specs: Vec<Spec<RGBA, HSV>> = vec![
+            Spec {
+                input: RGBA { r: 0, g: 0, b: 0, a: 1.0 },
+                output: HSV { h: 0.0, s: 0.0, v: 0.0 }
+            },
+            Spec {
+                input: RGBA { r: 255, g: 255, b: 255, a: 1.0 },
+                output: HSV { h: 0.0, s: 0.0, v: 1.0 }
+            },
+            Spec {
+                input: RGBA { r: 255, g: 0, b: 0, a: 1.0 },
+                output: HSV { h: 0.0, s: 1.0, v: 1.0 }
+            },
+            Spec {
+                input: RGBA { r: 0, g: 137, b: 255, a: 1.0 },
+                output: HSV { h: 208.0, s: 1.0, v: 1.0 }
+            }
+        ];
+
+        for (i, Spec { input, output }) in specs.iter().enumerate() {
+            let color = Color::create(input.r, input.g, input.b, input.a);
+            let hsv = color.hsv();
+
+            println!(":::: Running Spec: {}", i);
+            assert_eq!(hsv, *output);
+        }
+    }
+
+    #[test]
+    fn from_hsv() {
+        let specs: Vec<Spec<HSV, Color>> = vec![
+            Spec {
+                input: HSV { h: 0.0, s: 0.0, v: 0.0 },
+                output: Color { r: 0, g: 0, b: 0, a: 1.0 }
+            },
+            Spec {
+                input: HSV { h: 0.0, s: 0.0, v: 1.0 },
+                output: Color { r: 255, g: 255, b: 255, a: 1.0 }
+            },
+            Spec {
+                input: HSV { h: 0.0, s: 1.0, v: 1.0 },
+                output: Color { r: 255, g: 0, b: 0, a: 1.0 }
+            },
+            Spec {
+                input: HSV { h: 208.0, s: 1.0, v: 1.0 },
+                output: Color { r: 0, g: 136, b: 255, a: 1.0 }
+            }
+        ];
+
+        for (i, Spec { input, output }) in specs.iter().enumerate() {
+            let color = Color::from_hsv(input.h, input.s, input.v);
+
+            println!(":::: Running Spec: {}", i);
+            assert_eq!(color, *output);
+        }
+    }
+
     #[test]
     fn to_hsla_string() {
         let specs: Vec<Spec<RGBA, String>> = vec![
@@ -649,6 +1101,268 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_str() {
+        let specs: Vec<Spec<&str, Color>> = vec![
+            Spec {
+                input: "#0089ff",
+                output: Color { r: 0, g: 137, b: 255, a: 1.0 }
+            },
+            Spec {
+                input: "0089ff",
+                output: Color { r: 0, g: 137, b: 255, a: 1.0 }
+            },
+            Spec {
+                input: "#fff",
+                output: Color { r: 255, g: 255, b: 255, a: 1.0 }
+            },
+            Spec {
+                input: "#ff000080",
+                output: Color { r: 255, g: 0, b: 0, a: 128.0 / 255.0 }
+            },
+            Spec {
+                input: "rgb(0, 137, 255)",
+                output: Color { r: 0, g: 137, b: 255, a: 1.0 }
+            },
+            Spec {
+                input: "rgba(255, 0, 0, 0.5)",
+                output: Color { r: 255, g: 0, b: 0, a: 0.5 }
+            },
+            Spec {
+                input: "hsl(208, 100%, 50%)",
+                output: Color { r: 0, g: 136, b: 255, a: 1.0 }
+            },
+            Spec {
+                input: "hsla(0, 100%, 50%, 0.5)",
+                output: Color { r: 255, g: 0, b: 0, a: 0.5 }
+            }
+        ];
+
+        for (i, Spec { input, output }) in specs.iter().enumerate() {
+            println!(":::: Running Spec: {}", i);
+            assert_eq!(input.parse::<Color>().unwrap(), *output);
+        }
+    }
+
+    #[test]
+    fn from_str_errors() {
+        assert!("#WATNOPE".parse::<Color>().is_err());
+        assert!("✓".parse::<Color>().is_err());
+        assert!("✓✓".parse::<Color>().is_err());
+        assert!("rgb(0, 300, foo)".parse::<Color>().is_err());
+        assert!("rgb(0, 137)".parse::<Color>().is_err());
+        assert!("hsl(no parens".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn from_u32() {
+        let specs: Vec<Spec<u32, Color>> = vec![
+            Spec {
+                input: 0x000000ff,
+                output: Color { r: 0, g: 0, b: 0, a: 1.0 }
+            },
+            Spec {
+                input: 0xffffffff,
+                output: Color { r: 255, g: 255, b: 255, a: 1.0 }
+            },
+            Spec {
+                input: 0x0089ffff,
+                output: Color { r: 0, g: 137, b: 255, a: 1.0 }
+            },
+            Spec {
+                input: 0xff000080,
+                output: Color { r: 255, g: 0, b: 0, a: 128.0 / 255.0 }
+            }
+        ];
+
+        for (i, Spec { input, output }) in specs.iter().enumerate() {
+            println!(":::: Running Spec: {}", i);
+            assert_eq!(Color::from_u32(*input), *output);
+        }
+    }
+
+    #[test]
+    fn to_u32() {
+        let specs: Vec<Spec<Color, u32>> = vec![
+            Spec {
+                input: Color { r: 0, g: 0, b: 0, a: 1.0 },
+                output: 0x000000ff
+            },
+            Spec {
+                input: Color { r: 255, g: 255, b: 255, a: 1.0 },
+                output: 0xffffffff
+            },
+            Spec {
+                input: Color { r: 0, g: 137, b: 255, a: 1.0 },
+                output: 0x0089ffff
+            }
+        ];
+
+        for (i, Spec { input: color, output }) in specs.iter().enumerate() {
+            println!(":::: Running Spec: {}", i);
+            assert_eq!(color.to_u32(), *output);
+        }
+    }
+
+    #[test]
+    fn relative_luminance() {
+        let black = Color { r: 0, g: 0, b: 0, a: 1.0 };
+        let white = Color { r: 255, g: 255, b: 255, a: 1.0 };
+
+        assert_eq!(black.relative_luminance(), 0.0);
+        assert_eq!(white.relative_luminance(), 1.0);
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        let black = Color { r: 0, g: 0, b: 0, a: 1.0 };
+        let white = Color { r: 255, g: 255, b: 255, a: 1.0 };
+
+        assert_eq!(black.contrast_ratio(&white), 21.0);
+        assert_eq!(white.contrast_ratio(&black), 21.0);
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+    }
+
+    #[test]
+    fn meets_wcag_aa() {
+        let black = Color { r: 0, g: 0, b: 0, a: 1.0 };
+        let white = Color { r: 255, g: 255, b: 255, a: 1.0 };
+        let gray = Color { r: 128, g: 128, b: 128, a: 1.0 };
+
+        assert_eq!(black.meets_wcag_aa(&white), true);
+        assert_eq!(gray.meets_wcag_aa(&white), false);
+    }
+
+    #[test]
+    fn inverted() {
+        let specs: Vec<Spec<Color, Color>> = vec![
+            Spec {
+                input: Color { r: 0, g: 0, b: 0, a: 1.0 },
+                output: Color { r: 255, g: 255, b: 255, a: 1.0 }
+            },
+            Spec {
+                input: Color { r: 0, g: 137, b: 255, a: 0.5 },
+                output: Color { r: 255, g: 118, b: 0, a: 0.5 }
+            }
+        ];
+
+        for (i, Spec { input: color, output }) in specs.iter().enumerate() {
+            println!(":::: Running Spec: {}", i);
+            assert_eq!(color.inverted(), *output);
+        }
+    }
+
+    #[test]
+    fn complement() {
+        let color = Color { r: 255, g: 0, b: 0, a: 1.0 };
+        assert_eq!(color.complement(), Color { r: 0, g: 255, b: 255, a: 1.0 });
+    }
+
+    #[test]
+    fn lighten() {
+        let color = Color { r: 0, g: 0, b: 0, a: 1.0 };
+        assert_eq!(color.lighten(0.5), Color { r: 128, g: 128, b: 128, a: 1.0 });
+    }
+
+    #[test]
+    fn darken() {
+        let color = Color { r: 255, g: 255, b: 255, a: 1.0 };
+        assert_eq!(color.darken(0.5), Color { r: 128, g: 128, b: 128, a: 1.0 });
+    }
+
+    #[test]
+    fn saturate() {
+        let color = Color { r: 191, g: 64, b: 64, a: 1.0 };
+        assert_eq!(color.saturate(0.5), Color { r: 255, g: 0, b: 0, a: 1.0 });
+    }
+
+    #[test]
+    fn desaturate() {
+        let color = Color { r: 255, g: 0, b: 0, a: 1.0 };
+        assert_eq!(color.desaturate(0.5), Color { r: 191, g: 64, b: 64, a: 1.0 });
+    }
+
+    #[test]
+    fn lerp() {
+        let specs: Vec<Spec<(Color, Color, f64), Color>> = vec![
+            Spec {
+                input: (Color { r: 0, g: 0, b: 0, a: 1.0 }, Color { r: 255, g: 255, b: 255, a: 1.0 }, 0.0),
+                output: Color { r: 0, g: 0, b: 0, a: 1.0 }
+            },
+            Spec {
+                input: (Color { r: 0, g: 0, b: 0, a: 1.0 }, Color { r: 255, g: 255, b: 255, a: 1.0 }, 1.0),
+                output: Color { r: 255, g: 255, b: 255, a: 1.0 }
+            },
+            Spec {
+                input: (Color { r: 0, g: 0, b: 0, a: 1.0 }, Color { r: 255, g: 255, b: 255, a: 1.0 }, 0.5),
+                output: Color { r: 128, g: 128, b: 128, a: 1.0 }
+            },
+            Spec {
+                input: (Color { r: 0, g: 0, b: 0, a: 0.0 }, Color { r: 100, g: 200, b: 50, a: 1.0 }, 1.5),
+                output: Color { r: 100, g: 200, b: 50, a: 1.0 }
+            },
+            Spec {
+                input: (Color { r: 200, g: 0, b: 0, a: 1.0 }, Color { r: 0, g: 0, b: 200, a: 0.0 }, 0.25),
+                output: Color { r: 150, g: 0, b: 50, a: 0.75 }
+            }
+        ];
+
+        for (i, Spec { input: (from, to, t), output }) in specs.iter().enumerate() {
+            println!(":::: Running Spec: {}", i);
+            assert_eq!(from.lerp(to, *t), *output);
+        }
+    }
+
+    #[test]
+    fn gradient() {
+        let stops = vec![
+            Color { r: 0, g: 0, b: 0, a: 1.0 },
+            Color { r: 255, g: 255, b: 255, a: 1.0 }
+        ];
+        let colors = Color::gradient(&stops, 3);
+
+        assert_eq!(colors.len(), 3);
+        assert_eq!(colors[0], Color { r: 0, g: 0, b: 0, a: 1.0 });
+        assert_eq!(colors[1], Color { r: 128, g: 128, b: 128, a: 1.0 });
+        assert_eq!(colors[2], Color { r: 255, g: 255, b: 255, a: 1.0 });
+
+        assert_eq!(Color::gradient(&[], 4).len(), 0);
+        assert_eq!(Color::gradient(&stops, 0).len(), 0);
+
+        let single = vec![Color { r: 10, g: 20, b: 30, a: 1.0 }];
+        let repeated = Color::gradient(&single, 2);
+        assert_eq!(repeated.len(), 2);
+        assert_eq!(repeated[0], Color { r: 10, g: 20, b: 30, a: 1.0 });
+        assert_eq!(repeated[1], Color { r: 10, g: 20, b: 30, a: 1.0 });
+    }
+
+    #[test]
+    fn add() {
+        let a = Color { r: 200, g: 50, b: 0, a: 1.0 };
+        let b = Color { r: 100, g: 50, b: 255, a: 0.0 };
+
+        assert_eq!(a + b, Color { r: 255, g: 100, b: 255, a: 0.5 });
+    }
+
+    #[test]
+    fn sub() {
+        let a = Color { r: 100, g: 50, b: 0, a: 1.0 };
+        let b = Color { r: 200, g: 20, b: 0, a: 0.0 };
+
+        assert_eq!(a - b, Color { r: 0, g: 30, b: 0, a: 0.5 });
+    }
+
+    #[test]
+    fn mul() {
+        let color = Color { r: 100, g: 100, b: 100, a: 0.5 };
+
+        assert_eq!(color * 2.0, Color { r: 200, g: 200, b: 200, a: 0.5 });
+
+        let color = Color { r: 100, g: 100, b: 100, a: 0.5 };
+
+        assert_eq!(color * 3.0, Color { r: 255, g: 255, b: 255, a: 0.5 });
+    }
+
     #[test]
     fn is_transparent() {
         let specs: Vec<Spec<Color, bool>> = vec![